@@ -8,6 +8,7 @@
 #![no_std]
 
 use core::cell::UnsafeCell;
+use core::marker::PhantomData;
 use core::ptr;
 
 /// Just like [`Cell`] but with [volatile] read / write operations
@@ -55,11 +56,464 @@ impl<T> VolatileCell<T> {
     pub fn as_ptr(&self) -> *mut T {
         self.value.get()
     }
+
+    /// Performs a volatile read, ORs `mask` into the result, and writes it
+    /// back with a volatile write. Returns the previous value.
+    ///
+    /// NOTE this is **not** atomic: it takes a separate read and write, so
+    /// it must be wrapped in a critical section if it can race with an
+    /// interrupt or another core that also accesses this cell
+    #[inline(always)]
+    pub fn fetch_or(&self, mask: T) -> T
+        where T: Copy + core::ops::BitOr<Output = T>
+    {
+        let previous = self.get();
+        self.set(previous | mask);
+        previous
+    }
+
+    /// Performs a volatile read, ANDs `mask` into the result, and writes it
+    /// back with a volatile write. Returns the previous value.
+    ///
+    /// NOTE this is **not** atomic: it takes a separate read and write, so
+    /// it must be wrapped in a critical section if it can race with an
+    /// interrupt or another core that also accesses this cell
+    #[inline(always)]
+    pub fn fetch_and(&self, mask: T) -> T
+        where T: Copy + core::ops::BitAnd<Output = T>
+    {
+        let previous = self.get();
+        self.set(previous & mask);
+        previous
+    }
+
+    /// Performs a volatile read, XORs `mask` into the result, and writes it
+    /// back with a volatile write. Returns the previous value.
+    ///
+    /// NOTE this is **not** atomic: it takes a separate read and write, so
+    /// it must be wrapped in a critical section if it can race with an
+    /// interrupt or another core that also accesses this cell
+    #[inline(always)]
+    pub fn fetch_xor(&self, mask: T) -> T
+        where T: Copy + core::ops::BitXor<Output = T>
+    {
+        let previous = self.get();
+        self.set(previous ^ mask);
+        previous
+    }
+
+    /// Performs a volatile read, passes the value through `f`, and writes
+    /// the result back with a volatile write. Returns the previous value.
+    ///
+    /// NOTE this is **not** atomic: it takes a separate read and write, so
+    /// it must be wrapped in a critical section if it can race with an
+    /// interrupt or another core that also accesses this cell
+    #[inline(always)]
+    pub fn fetch_update<F>(&self, f: F) -> T
+        where T: Copy, F: FnOnce(T) -> T
+    {
+        let previous = self.get();
+        self.set(f(previous));
+        previous
+    }
+
+    /// Replaces the contained value with `value`, returning the old value
+    #[inline(always)]
+    pub fn replace(&self, value: T) -> T
+        where T: Copy
+    {
+        let previous = self.get();
+        self.set(value);
+        previous
+    }
+
+    /// Updates the contained value using `f`
+    #[inline(always)]
+    pub fn update<F>(&self, f: F)
+        where T: Copy, F: FnOnce(T) -> T
+    {
+        self.set(f(self.get()));
+    }
+}
+
+impl<T> From<T> for VolatileCell<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        VolatileCell::new(value)
+    }
+}
+
+impl<T> Default for VolatileCell<T>
+    where T: Default
+{
+    #[inline(always)]
+    fn default() -> Self {
+        VolatileCell::new(T::default())
+    }
 }
 
 // NOTE implicit because of `UnsafeCell`
 // unsafe impl<T> !Sync for VolatileCell<T> {}
 
+/// Like [`VolatileCell`] but for an address that's only known at runtime
+///
+/// Unlike `VolatileCell<T>`, which owns its `T`, a `VolatilePtr<T>` is just
+/// a `*mut T` with volatile read / write operations; it's meant for
+/// register blocks whose field addresses are computed from a base address
+/// rather than laid out in a `#[repr(C)]` struct
+///
+/// [`VolatileCell`]: struct.VolatileCell.html
+#[derive(Clone, Copy)]
+pub struct VolatilePtr<T> {
+    ptr: *mut T,
+}
+
+impl<T> VolatilePtr<T> {
+    /// Creates a new `VolatilePtr` from the given pointer
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid and aligned for `T`, and must point to a `T`
+    /// that stays live for as long as the returned `VolatilePtr` (and any
+    /// `VolatilePtr` derived from it via [`offset`]/[`add_offset`]) is used
+    ///
+    /// [`offset`]: #method.offset
+    /// [`add_offset`]: #method.add_offset
+    #[cfg(feature = "const-fn")]
+    pub const unsafe fn new(ptr: *mut T) -> Self {
+        VolatilePtr { ptr }
+    }
+
+    /// Creates a new `VolatilePtr` from the given pointer
+    ///
+    /// NOTE A `const fn` variant is available under the "const-fn" Cargo
+    /// feature
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid and aligned for `T`, and must point to a `T`
+    /// that stays live for as long as the returned `VolatilePtr` (and any
+    /// `VolatilePtr` derived from it via [`offset`]/[`add_offset`]) is used
+    ///
+    /// [`offset`]: #method.offset
+    /// [`add_offset`]: #method.add_offset
+    #[cfg(not(feature = "const-fn"))]
+    pub unsafe fn new(ptr: *mut T) -> Self {
+        VolatilePtr { ptr }
+    }
+
+    /// Returns a copy of the value stored at this address
+    #[inline(always)]
+    pub fn get(&self) -> T
+        where T: Copy
+    {
+        unsafe { ptr::read_volatile(self.ptr) }
+    }
+
+    /// Writes `value` to this address
+    #[inline(always)]
+    pub fn set(&self, value: T)
+        where T: Copy
+    {
+        unsafe { ptr::write_volatile(self.ptr, value) }
+    }
+
+    /// Returns the raw pointer wrapped by this `VolatilePtr`
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr
+    }
+
+    /// Returns a `VolatilePtr` pointing `count` elements of `T` ahead of
+    /// this one
+    ///
+    /// Equivalent to `self.as_ptr().offset(count)` wrapped back up in a
+    /// `VolatilePtr`
+    ///
+    /// # Safety
+    ///
+    /// `count` must stay within the bounds of the same allocation as
+    /// `self`, per the safety contract of [`pointer::offset`]
+    ///
+    /// [`pointer::offset`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset
+    #[inline(always)]
+    pub unsafe fn offset(self, count: isize) -> VolatilePtr<T> {
+        VolatilePtr::new(self.ptr.offset(count))
+    }
+
+    /// Returns a `VolatilePtr` pointing `count` elements of `T` ahead of
+    /// this one
+    ///
+    /// Equivalent to `self.as_ptr().add(count)` wrapped back up in a
+    /// `VolatilePtr`
+    ///
+    /// # Safety
+    ///
+    /// `count` must stay within the bounds of the same allocation as
+    /// `self`, per the safety contract of [`pointer::add`]
+    ///
+    /// [`pointer::add`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.add-1
+    #[inline(always)]
+    pub unsafe fn add_offset(self, count: usize) -> VolatilePtr<T> {
+        VolatilePtr::new(self.ptr.add(count))
+    }
+}
+
+/// A read-only register
+///
+/// It is up to the caller to make sure that `RO<T>` is only placed at
+/// addresses that are actually read-only.
+#[repr(transparent)]
+pub struct RO<T> {
+    register: VolatileCell<T>,
+}
+
+impl<T> RO<T>
+where
+    T: Copy,
+{
+    /// Creates a new read-only register wrapper around `value`
+    #[cfg(feature = "const-fn")]
+    pub const fn new(value: T) -> Self {
+        RO { register: VolatileCell::new(value) }
+    }
+
+    /// Creates a new read-only register wrapper around `value`
+    ///
+    /// NOTE A `const fn` variant is available under the "const-fn" Cargo
+    /// feature
+    #[cfg(not(feature = "const-fn"))]
+    pub fn new(value: T) -> Self {
+        RO { register: VolatileCell::new(value) }
+    }
+
+    /// Reads the value of the register
+    #[inline(always)]
+    pub fn read(&self) -> T {
+        self.register.get()
+    }
+}
+
+/// A write-only register
+#[repr(transparent)]
+pub struct WO<T> {
+    register: VolatileCell<T>,
+}
+
+impl<T> WO<T>
+where
+    T: Copy,
+{
+    /// Creates a new write-only register wrapper around `value`
+    #[cfg(feature = "const-fn")]
+    pub const fn new(value: T) -> Self {
+        WO { register: VolatileCell::new(value) }
+    }
+
+    /// Creates a new write-only register wrapper around `value`
+    ///
+    /// NOTE A `const fn` variant is available under the "const-fn" Cargo
+    /// feature
+    #[cfg(not(feature = "const-fn"))]
+    pub fn new(value: T) -> Self {
+        WO { register: VolatileCell::new(value) }
+    }
+
+    /// Writes `value` into the register
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that writing `value` to this register is
+    /// safe, e.g. that it doesn't put the peripheral in an invalid state
+    #[inline(always)]
+    pub unsafe fn write(&self, value: T) {
+        self.register.set(value)
+    }
+}
+
+/// A read-write register
+#[repr(transparent)]
+pub struct RW<T> {
+    register: VolatileCell<T>,
+}
+
+impl<T> RW<T>
+where
+    T: Copy,
+{
+    /// Creates a new read-write register wrapper around `value`
+    #[cfg(feature = "const-fn")]
+    pub const fn new(value: T) -> Self {
+        RW { register: VolatileCell::new(value) }
+    }
+
+    /// Creates a new read-write register wrapper around `value`
+    ///
+    /// NOTE A `const fn` variant is available under the "const-fn" Cargo
+    /// feature
+    #[cfg(not(feature = "const-fn"))]
+    pub fn new(value: T) -> Self {
+        RW { register: VolatileCell::new(value) }
+    }
+
+    /// Reads the value of the register
+    #[inline(always)]
+    pub fn read(&self) -> T {
+        self.register.get()
+    }
+
+    /// Writes `value` into the register
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that writing `value` to this register is
+    /// safe, e.g. that it doesn't put the peripheral in an invalid state
+    #[inline(always)]
+    pub unsafe fn write(&self, value: T) {
+        self.register.set(value)
+    }
+
+    /// Updates the contents of the register
+    ///
+    /// Reads the current value, passes it through `f`, and writes the
+    /// result back into the register
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that writing the value produced by `f`
+    /// to this register is safe, e.g. that it doesn't put the peripheral
+    /// in an invalid state
+    #[inline(always)]
+    pub unsafe fn modify<F>(&self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        self.register.set(f(self.register.get()))
+    }
+}
+
+/// Raw register type, e.g. `u32`, shared by a [`Readable`], [`Writable`]
+/// and/or [`Resettable`] register
+///
+/// [`Readable`]: trait.Readable.html
+/// [`Writable`]: trait.Writable.html
+/// [`Resettable`]: trait.Resettable.html
+pub trait RegisterSpec {
+    /// Raw register type, e.g. `u8`, `u16`, `u32`, ...
+    type Ux: Copy;
+}
+
+/// Marks a register as readable, and ties it to the [`Reader`] used to
+/// decode its raw bits
+///
+/// [`Reader`]: #associatedtype.Reader
+pub trait Readable: RegisterSpec {
+    /// Register reader, built from a `Self::Ux`
+    type Reader: From<Self::Ux>;
+}
+
+/// Marks a register as writable, and ties it to the [`Writer`] used to
+/// build its raw bits
+///
+/// [`Writer`]: #associatedtype.Writer
+pub trait Writable: RegisterSpec {
+    /// Register writer, built from and collapsed back into a `Self::Ux`
+    type Writer: From<Self::Ux> + Into<Self::Ux>;
+}
+
+/// Marks a register as having a known reset value
+pub trait Resettable: RegisterSpec {
+    /// Reset value of the register
+    fn reset_value() -> Self::Ux;
+}
+
+/// A generic register, parameterized over its [`RegisterSpec`]
+///
+/// [`RegisterSpec`]: trait.RegisterSpec.html
+#[repr(transparent)]
+pub struct Reg<REG: RegisterSpec> {
+    register: VolatileCell<REG::Ux>,
+    _marker: PhantomData<REG>,
+}
+
+impl<REG: RegisterSpec> Reg<REG> {
+    /// Creates a new register wrapper around `value`
+    #[cfg(feature = "const-fn")]
+    pub const fn new(value: REG::Ux) -> Self {
+        Reg { register: VolatileCell::new(value), _marker: PhantomData }
+    }
+
+    /// Creates a new register wrapper around `value`
+    ///
+    /// NOTE A `const fn` variant is available under the "const-fn" Cargo
+    /// feature
+    #[cfg(not(feature = "const-fn"))]
+    pub fn new(value: REG::Ux) -> Self {
+        Reg { register: VolatileCell::new(value), _marker: PhantomData }
+    }
+
+    /// Reads the contents of the register
+    #[inline(always)]
+    pub fn read(&self) -> REG::Reader
+    where
+        REG: Readable,
+    {
+        REG::Reader::from(self.register.get())
+    }
+
+    /// Calls `f` with a writer initialized from the reset value, then
+    /// writes the result to the register
+    #[inline(always)]
+    pub fn write<F>(&self, f: F)
+    where
+        REG: Writable + Resettable,
+        F: FnOnce(&mut REG::Writer) -> &mut REG::Writer,
+    {
+        let mut writer = REG::Writer::from(REG::reset_value());
+        f(&mut writer);
+        self.register.set(writer.into());
+    }
+
+    /// Calls `f` with a writer initialized from zero, then writes the
+    /// result to the register
+    #[inline(always)]
+    pub fn write_with_zero<F>(&self, f: F)
+    where
+        REG: Writable,
+        REG::Ux: Default,
+        F: FnOnce(&mut REG::Writer) -> &mut REG::Writer,
+    {
+        let mut writer = REG::Writer::from(REG::Ux::default());
+        f(&mut writer);
+        self.register.set(writer.into());
+    }
+
+    /// Reads the contents of the register, calls `f` with a reader and
+    /// writer both initialized from that value, then writes the result
+    /// of `f` back to the register
+    #[inline(always)]
+    pub fn modify<F>(&self, f: F)
+    where
+        REG: Readable + Writable,
+        for<'w> F: FnOnce(&REG::Reader, &'w mut REG::Writer) -> &'w mut REG::Writer,
+    {
+        let bits = self.register.get();
+        let reader = REG::Reader::from(bits);
+        let mut writer = REG::Writer::from(bits);
+        f(&reader, &mut writer);
+        self.register.set(writer.into());
+    }
+
+    /// Writes the reset value to the register
+    #[inline(always)]
+    pub fn reset(&self)
+    where
+        REG: Writable + Resettable,
+    {
+        self.write(|w| w)
+    }
+}
+
 /// Reset value of the register
 pub trait ResetValue {
     /// Reset value of the register
@@ -199,3 +653,62 @@ pub trait BitW<'a, W> {
     where
         Self: core::marker::Sized;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VolatileCell;
+
+    #[test]
+    fn fetch_or_sets_bits_and_returns_previous_value() {
+        let cell = VolatileCell::new(0b0001u8);
+        assert_eq!(cell.fetch_or(0b0010), 0b0001);
+        assert_eq!(cell.get(), 0b0011);
+    }
+
+    #[test]
+    fn fetch_and_clears_bits_and_returns_previous_value() {
+        let cell = VolatileCell::new(0b0011u8);
+        assert_eq!(cell.fetch_and(0b0001), 0b0011);
+        assert_eq!(cell.get(), 0b0001);
+    }
+
+    #[test]
+    fn fetch_xor_toggles_bits_and_returns_previous_value() {
+        let cell = VolatileCell::new(0b0110u8);
+        assert_eq!(cell.fetch_xor(0b0011), 0b0110);
+        assert_eq!(cell.get(), 0b0101);
+    }
+
+    #[test]
+    fn fetch_update_applies_closure_and_returns_previous_value() {
+        let cell = VolatileCell::new(41);
+        assert_eq!(cell.fetch_update(|x| x + 1), 41);
+        assert_eq!(cell.get(), 42);
+    }
+
+    #[test]
+    fn replace_swaps_value_and_returns_previous_value() {
+        let cell = VolatileCell::new(1);
+        assert_eq!(cell.replace(2), 1);
+        assert_eq!(cell.get(), 2);
+    }
+
+    #[test]
+    fn update_applies_closure_in_place() {
+        let cell = VolatileCell::new(1);
+        cell.update(|x| x + 1);
+        assert_eq!(cell.get(), 2);
+    }
+
+    #[test]
+    fn from_wraps_the_given_value() {
+        let cell = VolatileCell::from(42);
+        assert_eq!(cell.get(), 42);
+    }
+
+    #[test]
+    fn default_wraps_the_type_default() {
+        let cell: VolatileCell<u8> = VolatileCell::default();
+        assert_eq!(cell.get(), 0);
+    }
+}